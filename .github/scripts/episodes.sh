@@ -0,0 +1,60 @@
+# Helpers for the lint workflow's checks. Source this at the start of a step
+# with
+#
+#   source .github/scripts/episodes.sh
+
+# The slug jekyll gives an episode: its slug field if it has one, otherwise
+# its file name without the date.
+episode_slug() {
+  local slug
+  slug=$(sed -n 's/^slug: //p' "$1" | head -n1 | sed 's/"//g')
+  if [ -z "$slug" ]; then
+    slug=$(basename "$1" .md | sed 's/^[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]-//')
+  fi
+  echo "$slug"
+}
+
+# A date field of an episode (date unless another one is given) in seconds
+# since the epoch, or nothing if the episode doesn't have that field.
+episode_epoch() {
+  local value
+  value=$(sed -n "s/^${2:-date}: //p" "$1" | head -n1 | sed 's/"//g')
+  [ -n "$value" ] || return 0
+  date -d "$value" +%s
+}
+
+# Whether jekyll publishes an episode yet; ones dated in the future are held
+# back (see future: in _config.yml).
+episode_published() {
+  [[ $(episode_epoch "$1") -le $(date +%s) ]]
+}
+
+# Whether an episode is past its expires date.
+episode_expired() {
+  local expires
+  expires=$(episode_epoch "$1" expires)
+  [[ -n "$expires" && $expires -le $(date +%s) ]]
+}
+
+# Fills the associative array slugs with the slug of every episode.
+load_slugs() {
+  declare -gA slugs
+  local episode
+  for episode in _episodes/*/*.md; do
+    slugs[$(episode_slug "$episode")]=1
+  done
+}
+
+# Lists the episodes jekyll holds back because they are dated in the future,
+# and how many there are.
+report_held_back() {
+  local episode held=0
+  for episode in _episodes/*/*.md; do
+    if episode_published "$episode"; then
+      continue
+    fi
+    echo "$(basename "$episode"): held back until $(sed -n 's/^date: //p' "$episode")"
+    held=$((held + 1))
+  done
+  echo "::notice::$held episodes held back until their publish date"
+}
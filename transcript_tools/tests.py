@@ -10,6 +10,21 @@ class Tests(unittest.TestCase):
         cuts = split_front_matter(sample)
         self.assertEqual(cuts, ('---\ntitle: Foo\nfile: bar\n---\n', '\nBody\nhere.\n'))
 
+    def test_front_split_crlf(self):
+        sample = '---\r\ntitle: Foo\r\n---\r\nBody\r\n'
+        cuts = split_front_matter(sample)
+        self.assertEqual(cuts, ('---\r\ntitle: Foo\r\n---\r\n', 'Body\r\n'))
+
+    def test_front_split_trailing_space(self):
+        sample = '--- \ntitle: Foo\n---\t\nBody\n'
+        cuts = split_front_matter(sample)
+        self.assertEqual(cuts, ('--- \ntitle: Foo\n---\t\n', 'Body\n'))
+
+    def test_front_split_missing(self):
+        sample = '---\ntitle: Foo\nBody\n'
+        cuts = split_front_matter(sample)
+        self.assertEqual(cuts, ('', sample))
+
     def test_front_matter(self):
         sample = '---\ntitle: "A Title"\nfile: http://example/url\n---\n'
         errors = front_matter_check(sample)
@@ -23,6 +23,8 @@ import string
 from textwrap import wrap
 
 COMMON_NON_ASCII = set(['—'])
+# a front matter delimiter line; editors may leave trailing spaces or \r\n
+FRONT_MATTER_DIVIDER = re.compile(r'^---[ \t]*\r?\n', re.MULTILINE)
 
 def take(n, iterable):
     "Return first n items of the iterable as a list"
@@ -33,14 +35,13 @@ def split_front_matter(txt):
 
     returns (front_matter, everything_else)
     """
-    divider = '---\n'
-    first = txt.find(divider)
-    if first < 0:
+    first = FRONT_MATTER_DIVIDER.search(txt)
+    if not first:
         return ('', txt)
-    second = txt.find(divider, first+4)
-    if second < 0:
+    second = FRONT_MATTER_DIVIDER.search(txt, first.end())
+    if not second:
         return ('', txt)
-    cut_point = second + len(divider)
+    cut_point = second.end()
     return (txt[:cut_point], txt[cut_point:])
 
 def front_matter_check(txt):